@@ -0,0 +1,114 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::Interval;
+use std::cmp::Ordering;
+use std::fmt::Debug;
+
+/// A prebuilt index over the ordered, contiguous, non-overlapping intervals
+/// that [`super::find_intervals`] produces, for `O(log k)` lookups instead of
+/// the `O(k)` linear scan that [`super::quantize`] performs.
+///
+/// Build once from a slice of intervals with [`IntervalIndex::new`], then reuse
+/// it for any number of lookups via [`IntervalIndex::quantize`] or
+/// [`IntervalIndex::quantize_many`].
+///
+/// # Examples
+/// ```
+/// use oner_quantize::Interval;
+/// use oner_quantize::IntervalIndex;
+///
+/// let intervals = vec![
+///     Interval::lower(15, "x"),
+///     Interval::range(15, 20, "y"),
+///     Interval::upper(20, "z"),
+/// ];
+///
+/// let index = IntervalIndex::new(&intervals);
+///
+/// assert_eq!(index.quantize(10).map(|interval| interval.class()), Some(&"x"));
+/// assert_eq!(index.quantize(15).map(|interval| interval.class()), Some(&"y"));
+/// assert_eq!(index.quantize(99).map(|interval| interval.class()), Some(&"z"));
+/// ```
+pub struct IntervalIndex<'a, A, C> {
+    // The upper boundary (`below`) of every interval except the last, which is
+    // unbounded (`Upper` or `Infinite`). `boundaries[i]` is the `below` of
+    // `intervals[i]`, so the first boundary strictly greater than a value
+    // locates the containing interval.
+    boundaries: Vec<A>,
+    intervals: &'a [Interval<A, C>],
+}
+
+impl<'a, A, C> IntervalIndex<'a, A, C>
+where
+    A: PartialOrd + Copy,
+{
+    /// Build an index from `intervals`, as returned by `find_intervals`: ascending,
+    /// contiguous, non-overlapping, ending in either an `Upper` or a single `Infinite`.
+    pub fn new(intervals: &'a [Interval<A, C>]) -> Self {
+        let boundaries = intervals
+            .iter()
+            .filter_map(|interval| match interval {
+                Interval::Lower { below, .. } => Some(*below),
+                Interval::Range { below, .. } => Some(*below),
+                Interval::Upper { .. } | Interval::Infinite { .. } => None,
+            })
+            .collect();
+
+        IntervalIndex { boundaries, intervals }
+    }
+
+    /// Find which interval applies to a given attribute value, in `O(log k)` time.
+    pub fn quantize(&self, attribute_value: A) -> Option<&'a Interval<A, C>> {
+        let index = self
+            .boundaries
+            .binary_search_by(|boundary| {
+                if *boundary <= attribute_value {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                }
+            })
+            .unwrap_or_else(|insertion_point| insertion_point);
+
+        self.intervals.get(index)
+    }
+
+    /// Quantize a batch of values, reusing this index for each lookup.
+    pub fn quantize_many(&self, values: &[A]) -> Vec<Option<&'a Interval<A, C>>> {
+        values.iter().map(|&value| self.quantize(value)).collect()
+    }
+}
+
+impl<'a, A, C> Debug for IntervalIndex<'a, A, C>
+where
+    A: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IntervalIndex").field("boundaries", &self.boundaries).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IntervalIndex;
+    use crate::Interval;
+
+    #[test]
+    fn test_matches_linear_quantize() {
+        use crate::quantize;
+
+        let intervals = vec![
+            Interval::lower(10, "a"),
+            Interval::range(10, 100, "b"),
+            Interval::upper(100, "c"),
+        ];
+
+        let index = IntervalIndex::new(&intervals);
+
+        for value in [0, 9, 10, 11, 99, 100, 101, 1000] {
+            assert_eq!(index.quantize(value), quantize(&intervals, value));
+        }
+    }
+}
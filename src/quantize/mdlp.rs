@@ -0,0 +1,116 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+// Fayyad & Irani (1993) Minimum Description Length Principle (MDLP) discretization.
+//
+// Recursively considers every candidate cut `T` in the current segment, picks the
+// one minimising the class-information entropy `E(T)`, and accepts it only if the
+// resulting information gain clears the MDL threshold. Ties in `E(T)` are broken
+// by lowest index, for determinism.
+
+// Entropy of a set of classes, in bits: -sum(p_c * log2(p_c)) over class proportions.
+fn entropy<C: Eq + Hash>(classes: &[C]) -> f64 {
+    let n = classes.len();
+    if n == 0 {
+        return 0.0;
+    }
+
+    let mut counts: std::collections::HashMap<&C, usize> = std::collections::HashMap::new();
+    for class in classes {
+        *counts.entry(class).or_insert(0) += 1;
+    }
+
+    counts
+        .values()
+        .map(|&count| {
+            let p = count as f64 / n as f64;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+fn distinct_classes<C: Eq + Hash>(classes: &[C]) -> usize {
+    let distinct: HashSet<&C> = classes.iter().collect();
+    distinct.len()
+}
+
+// Find the candidate cut (relative to `classes`, i.e. in `0..classes.len()`) that
+// minimises the class-information entropy E(T), breaking ties by lowest index.
+fn best_cut<C: Eq + Hash>(classes: &[C], local_candidates: &[usize]) -> Option<(usize, f64)> {
+    let n = classes.len() as f64;
+    let mut best: Option<(usize, f64)> = None;
+
+    for &cut in local_candidates {
+        let e = (cut as f64 / n) * entropy(&classes[..cut])
+            + ((classes.len() - cut) as f64 / n) * entropy(&classes[cut..]);
+        match best {
+            Some((_, best_e)) if e >= best_e => {}
+            _ => best = Some((cut, e)),
+        }
+    }
+
+    best
+}
+
+fn mdlp_recurse<C>(candidates: &[usize], data: &[C], start: usize, end: usize, accepted: &mut Vec<usize>)
+where
+    C: Eq + Hash,
+{
+    let classes = &data[start..end];
+    let n = classes.len();
+    let ent_s = entropy(classes);
+    if ent_s == 0.0 || n < 2 {
+        return; // single class (or too small to split): never split
+    }
+
+    // Candidate cuts, relative to this segment, that fall strictly inside it:
+    let local_candidates: Vec<usize> =
+        candidates.iter().copied().filter(|&t| t > start && t < end).map(|t| t - start).collect();
+    if local_candidates.is_empty() {
+        return;
+    }
+
+    let (cut, e_t) = match best_cut(classes, &local_candidates) {
+        Some(best) => best,
+        None => return,
+    };
+
+    let gain = ent_s - e_t;
+
+    let left = &classes[..cut];
+    let right = &classes[cut..];
+    let k = distinct_classes(classes);
+    let k1 = distinct_classes(left);
+    let k2 = distinct_classes(right);
+
+    let delta = (3f64.powi(k as i32) - 2.0).log2()
+        - (k as f64 * ent_s - k1 as f64 * entropy(left) - k2 as f64 * entropy(right));
+    let threshold = ((n as f64 - 1.0).log2()) / n as f64 + delta / n as f64;
+
+    if gain > threshold {
+        let absolute_cut = start + cut;
+        accepted.push(absolute_cut);
+        mdlp_recurse(candidates, data, start, absolute_cut, accepted);
+        mdlp_recurse(candidates, data, absolute_cut, end, accepted);
+    }
+}
+
+// Apply MDLP to `data`, restricting candidate cuts to `candidate_splits` (the
+// indices where the attribute value changes), and return the accepted cuts in
+// ascending order.
+pub(crate) fn mdlp_splits<A, C>(candidate_splits: Vec<usize>, data: &[(&A, &C)]) -> Vec<usize>
+where
+    C: Eq + Hash + Copy,
+{
+    let classes: Vec<C> = data.iter().map(|pair| *pair.1).collect();
+
+    let mut accepted = Vec::new();
+    mdlp_recurse(&candidate_splits, &classes, 0, classes.len(), &mut accepted);
+    accepted.sort_unstable();
+    accepted.dedup();
+    accepted
+}
@@ -12,8 +12,53 @@ use std::hash::Hash;
 mod splits;
 use splits::{intervals_from_splits, trim_splits};
 
+mod index;
+pub use index::IntervalIndex;
+
+mod mdlp;
+mod strategy;
+pub use strategy::{Mdlp, SmallDisjunctTrim, SplitStrategy};
+
+pub mod oner;
+
+mod stats;
+pub use stats::{find_intervals_with_stats, IntervalStats};
+
+// Get the attribute values (plus associated class) in attribute sorted order, and
+// create a (tentative) candidate split each time the attribute value changes.
+//
+// The returned `Vec<usize>` contains indicies into the sorted data where we might
+// split the attribute into an interval boundary. That is, a value of 1 means the
+// attribute value at sorted[0] differs from sorted[1]; the split happens between
+// index 0 and 1 in that example.
+fn sorted_with_candidate_splits<A, C>(attribute: &[A], classes: &[C]) -> (Vec<(&A, &C)>, Vec<usize>)
+where
+    A: OrdSubset + Copy,
+{
+    let mut sorted: Vec<(&A, &C)> = Vec::new();
+    for (v, c) in attribute.iter().zip(classes.iter()) {
+        sorted.push((v, c));
+    }
+    sorted.ord_subset_sort_by_key(|pair| pair.0);
+
+    let mut split_index = Vec::new();
+    for (prev_index, ((cur_value, _cur_class), (prev_value, _prev_class))) in
+        sorted.iter().skip(1).zip(sorted.iter()).enumerate()
+    {
+        if cur_value > prev_value {
+            split_index.push(prev_index + 1);
+        }
+    }
+
+    (sorted, split_index)
+}
+
 /// Quantize the given `attribute` (aka feature, column) into an ordered list of `Intervals`.
 ///
+/// Uses [`SmallDisjunctTrim`] to decide which candidate splits to keep; see
+/// [`find_intervals_with_strategy`] to plug in a different [`SplitStrategy`],
+/// such as [`Mdlp`].
+///
 /// # Arguments
 ///
 /// * `attribute` - a single attribute, typically numeric, to be quantized.
@@ -44,34 +89,40 @@ where
     A: OrdSubset + Copy + Debug,
     C: Eq + Hash + Copy + Debug,
 {
-    // 1. Get the attribute values (plus associated class) in attribute sorted order:
-    let mut sorted: Vec<(&A, &C)> = Vec::new();
-    for (v, c) in attribute.iter().zip(classes.iter()) {
-        sorted.push((v, c));
-    }
-    sorted.ord_subset_sort_by_key(|pair| pair.0);
-
-    // 2. Create a (tentative) split each time the attribute value changes.
+    find_intervals_with_strategy(attribute, classes, &SmallDisjunctTrim { small })
+}
 
-    // `split_index` contains indicies into `sorted` where we might split the attribute into an interval boundary.
-    // That is, a value of 1 in `split_index` means the attribute value at sorted[0] differs from sorted[1].
-    // The split happens between index 0 and 1 in that example.
-    let mut split_index = Vec::new();
-    for (prev_index, ((cur_value, _cur_class), (prev_value, _prev_class))) in
-        sorted.iter().skip(1).zip(sorted.iter()).enumerate()
-    {
-        if cur_value > prev_value {
-            split_index.push(prev_index + 1);
-        }
-    }
+/// Like [`find_intervals`], but with the split-trimming policy supplied as a
+/// [`SplitStrategy`] rather than hardwired to the small-disjunct rule.
+///
+/// # Examples
+/// ```
+/// use oner_quantize::{find_intervals_with_strategy, Mdlp};
+///
+/// let attribute = vec![1, 10, 3, 1, 20, 30, 100];
+/// let classes   = vec!["a", "b", "a", "a", "b", "b", "c"];
+///
+/// let intervals = find_intervals_with_strategy(&attribute, &classes, &Mdlp);
+/// ```
+pub fn find_intervals_with_strategy<A, C, S>(
+    attribute: &[A],
+    classes: &[C],
+    strategy: &S,
+) -> Vec<Interval<A, C>>
+where
+    A: OrdSubset + Copy + Debug,
+    C: Eq + Hash + Copy + Debug,
+    S: SplitStrategy<A, C>,
+{
+    let (sorted, split_index) = sorted_with_candidate_splits(attribute, classes);
 
-    // 3. Remove splits that are too small:
-    let split_index_trimmed = trim_splits(split_index, small, &sorted);
+    // Remove splits the strategy doesn't want kept:
+    let split_index_trimmed = strategy.splits(split_index, &sorted);
 
-    // 4. Generate distinct intervals from the splits:
+    // Generate distinct intervals from the splits:
     let intervals: Vec<Interval<A, C>> = intervals_from_splits(split_index_trimmed, &sorted);
 
-    // 5. Remove redundant intervals:
+    // Remove redundant intervals:
     merge_neighbours_with_same_class(&intervals)
 }
 
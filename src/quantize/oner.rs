@@ -0,0 +1,118 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use super::{find_intervals, quantize};
+use crate::Interval;
+use ord_subset::OrdSubset;
+use std::cmp::Reverse;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// A trained "1R" (OneR) model: the single attribute (column) judged to be the
+/// best predictor of the classes it was trained on, together with the
+/// `Interval`s `find_intervals` produced for that attribute.
+///
+/// Build one with [`train`]; predict a class for a new row with [`OneRModel::predict`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OneRModel<A, C> {
+    pub attribute_index: usize,
+    pub intervals: Vec<Interval<A, C>>,
+}
+
+impl<A, C> OneRModel<A, C>
+where
+    A: PartialOrd + Copy,
+    C: Copy,
+{
+    /// Predict a class for `row` by quantizing the value in this model's chosen column.
+    pub fn predict(&self, row: &[A]) -> Option<&C> {
+        quantize(&self.intervals, row[self.attribute_index]).map(|interval| interval.class())
+    }
+}
+
+/// Train a OneR classifier over a column-major `dataset`: run `find_intervals` on
+/// every column, score each resulting ruleset by training accuracy (how many
+/// rows it labels correctly), and keep the best-scoring attribute. Ties are
+/// broken by fewest intervals, then by lowest column index.
+///
+/// Returns `None` if `dataset` has no columns.
+///
+/// # Arguments
+///
+/// * `dataset` - the attributes (columns), each the same length as `classes`.
+/// * `classes` - the corresponding class for each row.
+/// * `small` - the small disjunct threshold, passed through to `find_intervals`.
+///
+/// # Examples
+/// ```
+/// use oner_quantize::oner::train;
+///
+/// // Column 0 is noise; column 1 cleanly separates the classes:
+/// let dataset = vec![
+///     vec![1, 2, 1, 2, 1, 2],
+///     vec![1, 1, 1, 10, 10, 10],
+/// ];
+/// let classes = vec!["a", "a", "a", "b", "b", "b"];
+///
+/// let model = train(&dataset, &classes, 1).unwrap();
+///
+/// assert_eq!(model.attribute_index, 1);
+/// assert_eq!(model.predict(&[2, 1]), Some(&"a"));
+/// assert_eq!(model.predict(&[1, 10]), Some(&"b"));
+/// ```
+pub fn train<A, C>(dataset: &[Vec<A>], classes: &[C], small: usize) -> Option<OneRModel<A, C>>
+where
+    A: OrdSubset + Copy + Debug,
+    C: Eq + Hash + Copy + Debug,
+{
+    dataset
+        .iter()
+        .enumerate()
+        .map(|(attribute_index, attribute)| {
+            let intervals = find_intervals(attribute, classes, small);
+            let correct = attribute
+                .iter()
+                .zip(classes.iter())
+                .filter(|pair| {
+                    let (value, class) = *pair;
+                    quantize(&intervals, *value).map(|interval| interval.class()) == Some(class)
+                })
+                .count();
+            (attribute_index, intervals, correct)
+        })
+        .max_by_key(|(attribute_index, intervals, correct)| {
+            (*correct, Reverse(intervals.len()), Reverse(*attribute_index))
+        })
+        .map(|(attribute_index, intervals, _correct)| OneRModel { attribute_index, intervals })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::train;
+
+    #[test]
+    fn test_picks_the_separating_attribute() {
+        let dataset = vec![
+            vec![1, 2, 1, 2, 1, 2],    // noise: no relationship to the class
+            vec![1, 1, 1, 10, 10, 10], // a clean split at 10
+        ];
+        let classes = vec!["a", "a", "a", "b", "b", "b"];
+
+        let model = train(&dataset, &classes, 1).unwrap();
+
+        assert_eq!(model.attribute_index, 1);
+        assert_eq!(model.predict(&[1, 1]), Some(&"a"));
+        assert_eq!(model.predict(&[2, 1]), Some(&"a"));
+        assert_eq!(model.predict(&[1, 10]), Some(&"b"));
+        assert_eq!(model.predict(&[2, 10]), Some(&"b"));
+    }
+
+    #[test]
+    fn test_empty_dataset_has_no_model() {
+        let dataset: Vec<Vec<i32>> = vec![];
+        let classes: Vec<&str> = vec![];
+
+        assert!(train(&dataset, &classes, 1).is_none());
+    }
+}
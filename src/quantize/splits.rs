@@ -17,6 +17,21 @@ use std::hash::Hash;
 // Anything else is a range interval.
 // If there are no splits, then there's a single interval covering all values.
 pub fn intervals_from_splits<A, C>(splits: Vec<usize>, data: &[(&A, &C)]) -> Vec<Interval<A, C>>
+where
+    A: OrdSubset + Copy + Debug,
+    C: Eq + Hash + Copy + Debug,
+{
+    intervals_from_splits_with_bounds(splits, data).into_iter().map(|(interval, _bounds)| interval).collect()
+}
+
+// Same as `intervals_from_splits`, but also returns the `(start, until)` index range
+// in `data` each interval was built from, so callers (e.g. `stats::find_intervals_with_stats`)
+// can derive more from that range (such as the full class distribution) without
+// re-deriving the start/until bounds from `splits` themselves.
+pub(super) fn intervals_from_splits_with_bounds<A, C>(
+    splits: Vec<usize>,
+    data: &[(&A, &C)],
+) -> Vec<(Interval<A, C>, (usize, usize))>
 where
     A: OrdSubset + Copy + Debug,
     C: Eq + Hash + Copy + Debug,
@@ -32,23 +47,29 @@ where
         largest.unwrap_or_else(|| panic!("Found no classes for a split during quantization. This is likely a bug in this quantize implementation. Range is {} until {} in splits {:?} for data {:?}", start, until, &splits, data))
     };
 
-    let lower = |index: usize| Interval::Lower {
-        below: data[index].0.to_owned(),
-        class: most_frequent_class(0, index),
+    let lower = |index: usize| {
+        (Interval::Lower { below: data[index].0.to_owned(), class: most_frequent_class(0, index) }, (0, index))
     };
 
-    let upper = |index: usize| Interval::Upper {
-        from: data[index].0.to_owned(),
-        class: most_frequent_class(index, data.len()),
+    let upper = |index: usize| {
+        (
+            Interval::Upper { from: data[index].0.to_owned(), class: most_frequent_class(index, data.len()) },
+            (index, data.len()),
+        )
     };
 
-    let range = |index_start: usize, index_end: usize| Interval::Range {
-        from: data[index_start].0.to_owned(),
-        below: data[index_end].0.to_owned(),
-        class: most_frequent_class(index_start, index_end),
+    let range = |index_start: usize, index_end: usize| {
+        (
+            Interval::Range {
+                from: data[index_start].0.to_owned(),
+                below: data[index_end].0.to_owned(),
+                class: most_frequent_class(index_start, index_end),
+            },
+            (index_start, index_end),
+        )
     };
 
-    let infinite = || Interval::Infinite { class: most_frequent_class(0, data.len()) };
+    let infinite = || (Interval::Infinite { class: most_frequent_class(0, data.len()) }, (0, data.len()));
 
     match splits.len() {
         0 => vec![infinite()],
@@ -135,7 +156,7 @@ fn no_dominant_class<A, C: Eq + Hash>(
 
 // Using FxHasher for deterministic hashing.
 // This will give deterministic runs in the case of ties for most frequent class.
-fn frequency_count<T>(ts: &[T]) -> HashMap<&T, usize, BuildHasherDefault<FxHasher>>
+pub(super) fn frequency_count<T>(ts: &[T]) -> HashMap<&T, usize, BuildHasherDefault<FxHasher>>
 where
     T: Eq + Hash,
 {
@@ -0,0 +1,192 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use super::splits::{frequency_count, intervals_from_splits_with_bounds, trim_splits};
+use super::sorted_with_candidate_splits;
+use crate::Interval;
+use ord_subset::OrdSubset;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Per-interval class distribution, as produced by [`find_intervals_with_stats`].
+///
+/// Unlike `Interval`, which collapses each interval down to its majority class,
+/// `IntervalStats` retains every class count seen in the interval, so callers can
+/// threshold on [`IntervalStats::confidence`], spot [`IntervalStats::is_tie`]
+/// intervals, or apply their own tie-breaking rule instead of relying on the
+/// deterministic-but-opaque `FxHasher` ordering `find_intervals` uses.
+#[derive(Debug, Clone)]
+pub struct IntervalStats<A, C> {
+    pub interval: Interval<A, C>,
+    pub counts: HashMap<C, usize>,
+}
+
+// `#[derive(PartialEq)]` would only bound `C: PartialEq`, but `HashMap<C, usize>: PartialEq`
+// needs `C: Eq + Hash` too, so this is written out by hand.
+impl<A, C> PartialEq for IntervalStats<A, C>
+where
+    A: PartialEq,
+    C: Eq + Hash,
+    Interval<A, C>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.interval == other.interval && self.counts == other.counts
+    }
+}
+
+impl<A, C> IntervalStats<A, C>
+where
+    C: Eq + Hash + Copy,
+{
+    /// Total number of attribute values observed in this interval.
+    pub fn support(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Proportion of values in this interval belonging to the majority class.
+    pub fn confidence(&self) -> f64 {
+        let support = self.support();
+        if support == 0 {
+            return 0.0;
+        }
+        self.counts.values().copied().max().unwrap_or(0) as f64 / support as f64
+    }
+
+    /// True if two or more classes are tied for most frequent in this interval.
+    pub fn is_tie(&self) -> bool {
+        let max = self.counts.values().copied().max().unwrap_or(0);
+        self.counts.values().filter(|&&count| count == max).count() > 1
+    }
+}
+
+fn class_counts<A, C>(start: usize, until: usize, data: &[(&A, &C)]) -> HashMap<C, usize>
+where
+    C: Eq + Hash + Copy,
+{
+    let classes: Vec<C> = data[start..until].iter().map(|pair| *pair.1).collect();
+    frequency_count(&classes).into_iter().map(|(class, count)| (*class, count)).collect()
+}
+
+fn intervals_from_splits_with_stats<A, C>(splits: Vec<usize>, data: &[(&A, &C)]) -> Vec<IntervalStats<A, C>>
+where
+    A: OrdSubset + Copy + Debug,
+    C: Eq + Hash + Copy + Debug,
+{
+    intervals_from_splits_with_bounds(splits, data)
+        .into_iter()
+        .map(|(interval, (start, until))| IntervalStats { interval, counts: class_counts(start, until, data) })
+        .collect()
+}
+
+fn merge_counts<C: Eq + Hash + Copy>(mut a: HashMap<C, usize>, b: HashMap<C, usize>) -> HashMap<C, usize> {
+    for (class, count) in b {
+        *a.entry(class).or_insert(0) += count;
+    }
+    a
+}
+
+// Widen `first` to also cover `second`, keeping `first`'s class (the two are
+// only ever merged when their majority class agrees).
+fn extend_interval<A, C>(first: &Interval<A, C>, second: &Interval<A, C>) -> Interval<A, C>
+where
+    A: Copy + Debug,
+    C: Copy + Debug,
+{
+    match (first, second) {
+        (Interval::Lower { class, .. }, Interval::Range { below, .. }) => {
+            Interval::Lower { below: *below, class: *class }
+        }
+        (Interval::Lower { class, .. }, Interval::Upper { .. }) => Interval::Infinite { class: *class },
+        (Interval::Range { from, class, .. }, Interval::Range { below, .. }) => {
+            Interval::Range { from: *from, below: *below, class: *class }
+        }
+        (Interval::Range { from, class, .. }, Interval::Upper { .. }) => {
+            Interval::Upper { from: *from, class: *class }
+        }
+        (first, second) => panic!(
+            "Cannot merge non-adjacent interval kinds while combining interval statistics: {:?} and {:?}",
+            first, second
+        ),
+    }
+}
+
+// Merge adjacent stats whose majority class agrees, summing their counts and
+// widening the interval to cover both: the stats equivalent of
+// `interval::merge_neighbours_with_same_class`.
+fn merge_neighbours_with_same_class<A, C>(stats: Vec<IntervalStats<A, C>>) -> Vec<IntervalStats<A, C>>
+where
+    A: Copy + Debug,
+    C: Eq + Hash + Copy + Debug,
+{
+    let mut merged: Vec<IntervalStats<A, C>> = Vec::with_capacity(stats.len());
+
+    for stat in stats {
+        let combine_with_previous =
+            merged.last().map_or(false, |prev| prev.interval.class() == stat.interval.class());
+
+        if combine_with_previous {
+            let prev = merged.pop().expect("just checked merged.last() is Some");
+            merged.push(IntervalStats {
+                interval: extend_interval(&prev.interval, &stat.interval),
+                counts: merge_counts(prev.counts, stat.counts),
+            });
+        } else {
+            merged.push(stat);
+        }
+    }
+
+    merged
+}
+
+/// Like [`super::find_intervals`], but retains the full per-class counts for
+/// each interval instead of collapsing straight to a majority class.
+///
+/// # Examples
+/// ```
+/// use oner_quantize::find_intervals_with_stats;
+///
+/// let attribute = vec![64, 65, 68, 69, 70, 71, 72, 72, 75, 75, 80, 81, 83, 85];
+/// let classes   = vec!["p", "d", "p", "p", "p", "d", "p", "d", "p", "p", "d", "p", "p", "d"];
+///
+/// let stats = find_intervals_with_stats(&attribute, &classes, 3);
+///
+/// assert_eq!(stats.len(), 2);
+/// assert_eq!(stats[0].support(), 13);
+/// assert!(!stats[0].is_tie());
+/// ```
+pub fn find_intervals_with_stats<A, C>(attribute: &[A], classes: &[C], small: usize) -> Vec<IntervalStats<A, C>>
+where
+    A: OrdSubset + Copy + Debug,
+    C: Eq + Hash + Copy + Debug,
+{
+    let (sorted, split_index) = sorted_with_candidate_splits(attribute, classes);
+    let split_index_trimmed = trim_splits(split_index, small, &sorted);
+    let stats = intervals_from_splits_with_stats(split_index_trimmed, &sorted);
+    merge_neighbours_with_same_class(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find_intervals_with_stats;
+
+    #[test]
+    fn test_golf_example_retains_counts() {
+        // Same data as `quantize::tests::test_golf_example`, from:
+        // Nevill-Manning, Holmes & Witten (1995)  _The Development of Holte's 1R Classifier_, p. 2
+        let attribute = vec![64, 65, 68, 69, 70, 71, 72, 72, 75, 75, 80, 81, 83, 85];
+        let classes = vec!["p", "d", "p", "p", "p", "d", "p", "d", "p", "p", "d", "p", "p", "d"];
+
+        let stats = find_intervals_with_stats(&attribute, &classes, 3);
+
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].interval.class(), &"p");
+        assert_eq!(stats[0].support(), 13);
+        assert_eq!(stats[0].counts["p"], 9);
+        assert_eq!(stats[0].counts["d"], 4);
+        assert!(!stats[0].is_tie());
+        assert_eq!(stats[1].interval.class(), &"d");
+        assert_eq!(stats[1].support(), 1);
+    }
+}
@@ -0,0 +1,82 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use super::mdlp::mdlp_splits;
+use super::splits::trim_splits;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// A pluggable policy for turning the candidate split points (every index where
+/// the sorted attribute value changes) into the subset that should become
+/// interval boundaries.
+///
+/// [`super::find_intervals`] uses [`SmallDisjunctTrim`]; pass [`Mdlp`] (or your
+/// own implementation) to [`super::find_intervals_with_strategy`] for an
+/// entropy-driven alternative.
+pub trait SplitStrategy<A, C> {
+    fn splits(&self, candidate_splits: Vec<usize>, data: &[(&A, &C)]) -> Vec<usize>;
+}
+
+/// The default strategy: drop splits that create a small disjunct (no class
+/// with more than `small` values) or that don't change the dominant class, as
+/// used by `find_intervals`.
+pub struct SmallDisjunctTrim {
+    pub small: usize,
+}
+
+impl<A, C> SplitStrategy<A, C> for SmallDisjunctTrim
+where
+    C: Eq + Hash + Debug,
+{
+    fn splits(&self, candidate_splits: Vec<usize>, data: &[(&A, &C)]) -> Vec<usize> {
+        trim_splits(candidate_splits, self.small, data)
+    }
+}
+
+/// Fayyad & Irani (1993) Minimum Description Length Principle (MDLP) discretizer.
+///
+/// Recursively splits each segment on the candidate cut that minimises the
+/// class-information entropy, stopping once the information gain no longer
+/// clears the MDL threshold. Segments with a single class are never split.
+pub struct Mdlp;
+
+impl<A, C> SplitStrategy<A, C> for Mdlp
+where
+    C: Eq + Hash + Copy,
+{
+    fn splits(&self, candidate_splits: Vec<usize>, data: &[(&A, &C)]) -> Vec<usize> {
+        mdlp_splits(candidate_splits, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Mdlp;
+    use crate::find_intervals_with_strategy;
+    use crate::Interval;
+
+    #[test]
+    fn test_single_class_is_never_split() {
+        let attribute = vec![1, 2, 3, 4, 5];
+        let classes = vec!["a", "a", "a", "a", "a"];
+
+        let actual = find_intervals_with_strategy(&attribute, &classes, &Mdlp);
+
+        assert_eq!(vec![Interval::infinite("a")], actual);
+    }
+
+    #[test]
+    fn test_golf_example() {
+        // Same data as the small-disjunct test in `quantize::tests`, from:
+        // Nevill-Manning, Holmes & Witten (1995)  _The Development of Holte's 1R Classifier_, p. 2
+        let attribute = vec![64, 65, 68, 69, 70, 71, 72, 72, 75, 75, 80, 81, 83, 85];
+        let classes = vec!["p", "d", "p", "p", "p", "d", "p", "d", "p", "p", "d", "p", "p", "d"];
+
+        let actual = find_intervals_with_strategy(&attribute, &classes, &Mdlp);
+
+        // MDLP finds no cut whose information gain clears the MDL threshold for
+        // this (noisy, small) dataset, so it keeps the whole attribute as one interval.
+        assert_eq!(vec![Interval::infinite("p")], actual);
+    }
+}